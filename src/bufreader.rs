@@ -0,0 +1,140 @@
+use std::cell::RefCell;
+use std::cmp::min;
+
+use crate::{ReadAt, Result, Size};
+
+/// The default size of the internal buffer, in bytes.
+const DEFAULT_CAPACITY: usize = 8 * 1024;
+
+/// Wraps a `ReadAt`, caching a sliding window of its contents so repeated
+/// small reads near the same offset don't all turn into calls to the inner
+/// `read_at`.
+///
+/// Unlike `std`'s `BufReader`, positioned reads can jump around, so
+/// `BufReaderAt` doesn't assume sequential access: it just remembers the
+/// last region it read and reuses it while requests keep landing inside it,
+/// refilling starting at the new offset on a miss.
+///
+/// `read_at` takes `&self`, using a `RefCell` internally so a `BufReaderAt`
+/// can be aliased through shared references the way other `ReadAt` impls
+/// are. That `RefCell` makes `BufReaderAt` `!Sync`, though: it's only safe
+/// to alias within a single thread, and a re-entrant `read_at` call on the
+/// same instance (e.g. from inside another `read_at`) will panic.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::io;
+/// use positioned_io::{BufReaderAt, ReadAt};
+///
+/// # fn foo() -> io::Result<()> {
+/// let data = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+/// let buffered = BufReaderAt::with_capacity(4, &data[..]);
+///
+/// // This read misses and fills the buffer with `[0, 1, 2, 3]`.
+/// let mut buf = [0; 2];
+/// buffered.read_at(0, &mut buf)?;
+/// assert_eq!(buf, [0, 1]);
+///
+/// // This read hits the same buffer.
+/// buffered.read_at(2, &mut buf)?;
+/// assert_eq!(buf, [2, 3]);
+///
+/// // This read misses, refilling the buffer starting at offset 6.
+/// buffered.read_at(6, &mut buf)?;
+/// assert_eq!(buf, [6, 7]);
+/// # Ok(())
+/// # }
+/// # fn main() { foo().unwrap(); }
+/// ```
+#[derive(Debug)]
+pub struct BufReaderAt<I> {
+    inner: I,
+    buf: RefCell<Buffer>,
+}
+
+impl<I> BufReaderAt<I> {
+    /// Wrap `inner`, using a default-sized internal buffer.
+    pub fn new(inner: I) -> Self {
+        Self::with_capacity(DEFAULT_CAPACITY, inner)
+    }
+
+    /// Wrap `inner`, using an internal buffer that refills `capacity` bytes
+    /// at a time.
+    pub fn with_capacity(capacity: usize, inner: I) -> Self {
+        BufReaderAt {
+            inner,
+            buf: RefCell::new(Buffer::new(capacity)),
+        }
+    }
+
+    /// Consume `self`, returning the inner `ReadAt`.
+    pub fn into_inner(self) -> I {
+        self.inner
+    }
+
+    /// Borrow the inner `ReadAt`.
+    pub fn get_ref(&self) -> &I {
+        &self.inner
+    }
+}
+
+impl<I: ReadAt> ReadAt for BufReaderAt<I> {
+    fn read_at(&self, pos: u64, buf: &mut [u8]) -> Result<usize> {
+        let mut buffer = self.buf.borrow_mut();
+        if !buffer.contains(pos) {
+            buffer.refill(&self.inner, pos)?;
+        }
+        Ok(buffer.copy_out(pos, buf))
+    }
+}
+
+impl<I: Size> Size for BufReaderAt<I> {
+    fn size(&self) -> Result<Option<u64>> {
+        self.inner.size()
+    }
+}
+
+/// The sliding window of bytes cached by a `BufReaderAt`.
+///
+/// Keeping the bookkeeping here (rather than spread across `read_at`) keeps
+/// the hot path down to a bounds check in `contains` plus a
+/// `copy_from_slice` in `copy_out`.
+#[derive(Debug)]
+struct Buffer {
+    data: Vec<u8>,
+    start: u64,
+    filled: usize,
+}
+
+impl Buffer {
+    fn new(capacity: usize) -> Self {
+        Buffer {
+            data: vec![0; capacity],
+            start: 0,
+            filled: 0,
+        }
+    }
+
+    /// Is `pos` covered by the currently filled region?
+    fn contains(&self, pos: u64) -> bool {
+        self.filled > 0 && pos >= self.start && pos - self.start < self.filled as u64
+    }
+
+    /// Refill the buffer by reading up to its capacity starting at `pos`.
+    fn refill<I: ReadAt>(&mut self, inner: &I, pos: u64) -> Result<()> {
+        self.filled = inner.read_at(pos, &mut self.data)?;
+        self.start = pos;
+        Ok(())
+    }
+
+    /// Copy as much of `out` as is available starting at `pos`, which must
+    /// be covered by the buffer (see `contains`).
+    fn copy_out(&self, pos: u64, out: &mut [u8]) -> usize {
+        let offset = (pos - self.start) as usize;
+        let avail = self.filled - offset;
+        let bytes = min(avail, out.len());
+        out[..bytes].copy_from_slice(&self.data[offset..offset + bytes]);
+        bytes
+    }
+}
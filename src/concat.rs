@@ -0,0 +1,159 @@
+use std::cmp::min;
+
+use crate::{Error, ErrorKind, ReadAt, Result, Size, WriteAt};
+
+/// Presents a number of sized `ReadAt`/`WriteAt` segments as a single,
+/// contiguous positioned I/O object.
+///
+/// This is the natural complement to [`Slice`][Slice], which narrows one
+/// source down to a sub-range: `Concat` joins several sources end to end,
+/// translating a single global `pos` into the right segment and local
+/// offset, and splitting any read or write that straddles a boundary across
+/// the segments it touches. It's useful for treating split files,
+/// multi-part uploads, or a header paired with a body as one seekable
+/// stream through [`Cursor`][Cursor].
+///
+/// Building a `Concat` queries every segment's [`size`][Size::size] once, up
+/// front, to build an offset table; after that, locating the segment for a
+/// given `pos` is a binary search rather than a linear scan.
+///
+/// [Slice]: struct.Slice.html
+/// [Cursor]: struct.Cursor.html
+/// [Size::size]: trait.Size.html#method.size
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::io;
+/// use positioned_io::{Concat, ReadAt};
+///
+/// # fn foo() -> io::Result<()> {
+/// let a = [0, 1, 2, 3];
+/// let b = [4, 5, 6, 7, 8, 9];
+/// let whole = Concat::new(vec![&a[..], &b[..]])?;
+///
+/// let mut buf = [0; 4];
+/// whole.read_at(2, &mut buf)?;
+/// assert_eq!(buf, [2, 3, 4, 5]);
+/// # Ok(())
+/// # }
+/// # fn main() { foo().unwrap(); }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Concat<I> {
+    segments: Vec<I>,
+    // `offsets[i]` is the global position where `segments[i]` starts;
+    // `offsets[segments.len()]` is the total size.
+    offsets: Vec<u64>,
+}
+
+impl<I: Size> Concat<I> {
+    /// Join `segments` into a single contiguous address space, in order.
+    pub fn new(segments: impl IntoIterator<Item = I>) -> Result<Self> {
+        let segments: Vec<I> = segments.into_iter().collect();
+        let mut offsets = Vec::with_capacity(segments.len() + 1);
+        let mut total = 0u64;
+        offsets.push(0);
+        for segment in &segments {
+            let size = segment
+                .size()?
+                .ok_or_else(|| Error::new(ErrorKind::InvalidData, "unknown segment size"))?;
+            total += size;
+            offsets.push(total);
+        }
+        Ok(Concat { segments, offsets })
+    }
+}
+
+impl<I> Concat<I> {
+    /// The total size of all segments put together.
+    fn total(&self) -> u64 {
+        *self.offsets.last().unwrap_or(&0)
+    }
+
+    /// The index of the segment that covers `pos`, assuming `pos < total()`.
+    ///
+    /// Binary search over the offset table built at construction time.
+    fn segment_for(&self, pos: u64) -> usize {
+        let mut lo = 0;
+        let mut hi = self.segments.len();
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if self.offsets[mid + 1] <= pos {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        lo
+    }
+}
+
+impl<I: ReadAt> ReadAt for Concat<I> {
+    fn read_at(&self, pos: u64, buf: &mut [u8]) -> Result<usize> {
+        if pos >= self.total() || buf.is_empty() {
+            return Ok(0);
+        }
+
+        let mut seg = self.segment_for(pos);
+        let mut seg_pos = pos - self.offsets[seg];
+        let mut done = 0;
+        while done < buf.len() && seg < self.segments.len() {
+            let seg_size = self.offsets[seg + 1] - self.offsets[seg];
+            let want = min(buf.len() - done, (seg_size - seg_pos) as usize);
+            let got = self.segments[seg].read_at(seg_pos, &mut buf[done..done + want])?;
+            done += got;
+            if got < want {
+                // The segment came up short; don't paper over it by moving
+                // on to the next one.
+                break;
+            }
+            seg_pos += got as u64;
+            if seg_pos >= seg_size {
+                seg += 1;
+                seg_pos = 0;
+            }
+        }
+        Ok(done)
+    }
+}
+
+impl<I: WriteAt> WriteAt for Concat<I> {
+    fn write_at(&mut self, pos: u64, buf: &[u8]) -> Result<usize> {
+        if pos >= self.total() || buf.is_empty() {
+            return Ok(0);
+        }
+
+        let mut seg = self.segment_for(pos);
+        let mut seg_pos = pos - self.offsets[seg];
+        let mut done = 0;
+        while done < buf.len() && seg < self.segments.len() {
+            let seg_size = self.offsets[seg + 1] - self.offsets[seg];
+            let want = min(buf.len() - done, (seg_size - seg_pos) as usize);
+            let put = self.segments[seg].write_at(seg_pos, &buf[done..done + want])?;
+            done += put;
+            if put < want {
+                break;
+            }
+            seg_pos += put as u64;
+            if seg_pos >= seg_size {
+                seg += 1;
+                seg_pos = 0;
+            }
+        }
+        Ok(done)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        for segment in &mut self.segments {
+            segment.flush()?;
+        }
+        Ok(())
+    }
+}
+
+impl<I> Size for Concat<I> {
+    fn size(&self) -> Result<Option<u64>> {
+        Ok(Some(self.total()))
+    }
+}
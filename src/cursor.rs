@@ -1,7 +1,8 @@
-use std::io;
+#[cfg(feature = "std")]
 use std::io::{Read, Seek, SeekFrom, Write};
 
-use super::{ReadAt, Size, WriteAt};
+#[cfg(feature = "std")]
+use crate::{Error, ErrorKind, ReadAt, Size, WriteAt};
 
 /// Adapts a `ReadAt` or `WriteAt` into a `Read` or `Write`.
 ///
@@ -105,45 +106,342 @@ impl<I> Cursor<I> {
     }
 }
 
+#[cfg(feature = "std")]
 impl<I: ReadAt> Read for Cursor<I> {
-    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
         let bytes = self.get_ref().read_at(self.pos, buf)?;
         self.pos += bytes as u64;
         Ok(bytes)
     }
+
+    fn read_vectored(&mut self, bufs: &mut [std::io::IoSliceMut<'_>]) -> std::io::Result<usize> {
+        let bytes = self.get_ref().read_vectored_at(self.pos, bufs)?;
+        self.pos += bytes as u64;
+        Ok(bytes)
+    }
 }
 
+#[cfg(feature = "std")]
 impl<I: WriteAt> Write for Cursor<I> {
-    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
         let pos = self.pos;
         let bytes = self.get_mut().write_at(pos, buf)?;
         self.pos += bytes as u64;
         Ok(bytes)
     }
 
+    fn write_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) -> std::io::Result<usize> {
+        let pos = self.pos;
+        let bytes = self.get_mut().write_vectored_at(pos, bufs)?;
+        self.pos += bytes as u64;
+        Ok(bytes)
+    }
+
     #[inline]
-    fn flush(&mut self) -> io::Result<()> {
+    fn flush(&mut self) -> std::io::Result<()> {
         WriteAt::flush(self.get_mut())
     }
 }
 
+#[cfg(feature = "std")]
 impl<I: Size> Seek for Cursor<I> {
-    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        match pos {
+            SeekFrom::Start(p) => self.pos = p,
+            SeekFrom::Current(p) => {
+                let pos = self.pos as i64 + p;
+                if pos < 0 {
+                    return Err(Error::new(ErrorKind::InvalidInput, "seek to a negative position"));
+                }
+                self.pos = pos as u64;
+            }
+            SeekFrom::End(p) => {
+                let end = self.io.size()?;
+                self.pos = match end {
+                    Some(end) => (end as i64 + p) as u64,
+                    None => return Err(Error::new(ErrorKind::InvalidInput, "seek from unknown end")),
+                }
+            }
+        }
+        Ok(self.pos)
+    }
+}
+
+/// Like [`Cursor`][Cursor], and queries the size of the inner object every
+/// time you seek from the end.
+///
+/// Seeking behaves exactly as it does on [`Cursor`][Cursor], which also
+/// calls `size()` on every `SeekFrom::End` rather than caching it.
+///
+/// [Cursor]: struct.Cursor.html
+#[derive(Debug, Clone)]
+pub struct SizeCursor<I> {
+    io: I,
+    pos: u64,
+}
+
+impl<I> SizeCursor<I> {
+    /// Create a new `SizeCursor` which starts reading at a specified offset.
+    #[inline]
+    pub fn new_pos(io: I, pos: u64) -> Self {
+        SizeCursor { io, pos }
+    }
+
+    /// Create a new `SizeCursor` which starts reading at offset zero.
+    #[inline]
+    pub fn new(io: I) -> Self {
+        Self::new_pos(io, 0)
+    }
+
+    /// Consume `self` and yield the inner `ReadAt` or `WriteAt`.
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.io
+    }
+
+    /// Borrow the inner `ReadAt` or `WriteAt`.
+    #[inline]
+    pub fn get_ref(&self) -> &I {
+        &self.io
+    }
+
+    /// Borrow the inner `ReadAt` or `WriteAt` mutably.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut I {
+        &mut self.io
+    }
+
+    /// Get the current read/write position.
+    #[inline]
+    pub fn position(&self) -> u64 {
+        self.pos
+    }
+
+    /// Set the current read/write position.
+    #[inline]
+    pub fn set_position(&mut self, pos: u64) {
+        self.pos = pos;
+    }
+}
+
+#[cfg(feature = "std")]
+impl<I: ReadAt> Read for SizeCursor<I> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let bytes = self.get_ref().read_at(self.pos, buf)?;
+        self.pos += bytes as u64;
+        Ok(bytes)
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [std::io::IoSliceMut<'_>]) -> std::io::Result<usize> {
+        let bytes = self.get_ref().read_vectored_at(self.pos, bufs)?;
+        self.pos += bytes as u64;
+        Ok(bytes)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<I: WriteAt> Write for SizeCursor<I> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let pos = self.pos;
+        let bytes = self.get_mut().write_at(pos, buf)?;
+        self.pos += bytes as u64;
+        Ok(bytes)
+    }
+
+    fn write_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) -> std::io::Result<usize> {
+        let pos = self.pos;
+        let bytes = self.get_mut().write_vectored_at(pos, bufs)?;
+        self.pos += bytes as u64;
+        Ok(bytes)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        WriteAt::flush(self.get_mut())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<I: Size> Seek for SizeCursor<I> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
         match pos {
             SeekFrom::Start(p) => self.pos = p,
             SeekFrom::Current(p) => {
                 let pos = self.pos as i64 + p;
                 if pos < 0 {
-                    return Err(io::Error::new(io::ErrorKind::InvalidInput, "seek to a negative position"));
+                    return Err(Error::new(ErrorKind::InvalidInput, "seek to a negative position"));
                 }
                 self.pos = pos as u64;
             }
             SeekFrom::End(p) => {
+                // As in `Cursor`, we re-query the size every time, in case
+                // the inner object has grown or shrunk since we last sought
+                // from the end.
                 let end = self.io.size()?;
                 self.pos = match end {
                     Some(end) => (end as i64 + p) as u64,
-                    None => return Err(io::Error::new(io::ErrorKind::InvalidInput, "seek from unknown end")),
+                    None => return Err(Error::new(ErrorKind::InvalidInput, "seek from unknown end")),
+                }
+            }
+        }
+        Ok(self.pos)
+    }
+}
+
+/// Like [`Cursor`][Cursor], but stores an explicit length so `SeekFrom::End`
+/// works without requiring `I: Size`.
+///
+/// This is useful for `ReadAt` sources (a remote disk, say) where the length
+/// is known up front but calling `size()` is expensive or impossible. The
+/// stored length is only ever used to interpret `SeekFrom::End`: it's never
+/// checked against the inner object, and reads past the real end still just
+/// return `0` the way any other `ReadAt` would. Seeking past `length` is
+/// permitted, matching [`Cursor`][Cursor]'s behavior for `SeekFrom::Start`.
+///
+/// [Cursor]: struct.Cursor.html
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::io;
+/// use std::io::{Read, Seek, SeekFrom};
+/// use positioned_io::LenCursor;
+///
+/// # fn foo() -> io::Result<()> {
+/// let data = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+///
+/// // The reported length doesn't have to match `data`'s real length.
+/// let mut curs = LenCursor::new(&data[..], 8);
+///
+/// let pos = curs.seek(SeekFrom::End(-3))?;
+/// assert_eq!(pos, 5);
+///
+/// let mut buf = [0; 3];
+/// curs.read_exact(&mut buf)?;
+/// assert_eq!(buf, [5, 6, 7]);
+/// # Ok(())
+/// # }
+/// # fn main() { foo().unwrap(); }
+/// ```
+#[derive(Debug, Clone)]
+pub struct LenCursor<I> {
+    io: I,
+    pos: u64,
+    length: u64,
+}
+
+impl<I> LenCursor<I> {
+    /// Create a new `LenCursor` which starts reading at a specified offset.
+    ///
+    /// `length` is used only to interpret `SeekFrom::End`.
+    #[inline]
+    pub fn new_pos(io: I, length: u64, pos: u64) -> Self {
+        LenCursor { io, pos, length }
+    }
+
+    /// Create a new `LenCursor` which starts reading at offset zero.
+    ///
+    /// `length` is used only to interpret `SeekFrom::End`.
+    #[inline]
+    pub fn new(io: I, length: u64) -> Self {
+        Self::new_pos(io, length, 0)
+    }
+
+    /// Consume `self` and yield the inner `ReadAt` or `WriteAt`.
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.io
+    }
+
+    /// Borrow the inner `ReadAt` or `WriteAt`.
+    #[inline]
+    pub fn get_ref(&self) -> &I {
+        &self.io
+    }
+
+    /// Borrow the inner `ReadAt` or `WriteAt` mutably.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut I {
+        &mut self.io
+    }
+
+    /// Get the current read/write position.
+    #[inline]
+    pub fn position(&self) -> u64 {
+        self.pos
+    }
+
+    /// Set the current read/write position.
+    #[inline]
+    pub fn set_position(&mut self, pos: u64) {
+        self.pos = pos;
+    }
+
+    /// Get the length used to interpret `SeekFrom::End`.
+    #[inline]
+    pub fn len(&self) -> u64 {
+        self.length
+    }
+
+    /// Returns `true` if the length used to interpret `SeekFrom::End` is
+    /// zero.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+#[cfg(feature = "std")]
+impl<I: ReadAt> Read for LenCursor<I> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let bytes = self.get_ref().read_at(self.pos, buf)?;
+        self.pos += bytes as u64;
+        Ok(bytes)
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [std::io::IoSliceMut<'_>]) -> std::io::Result<usize> {
+        let bytes = self.get_ref().read_vectored_at(self.pos, bufs)?;
+        self.pos += bytes as u64;
+        Ok(bytes)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<I: WriteAt> Write for LenCursor<I> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let pos = self.pos;
+        let bytes = self.get_mut().write_at(pos, buf)?;
+        self.pos += bytes as u64;
+        Ok(bytes)
+    }
+
+    fn write_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) -> std::io::Result<usize> {
+        let pos = self.pos;
+        let bytes = self.get_mut().write_vectored_at(pos, bufs)?;
+        self.pos += bytes as u64;
+        Ok(bytes)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        WriteAt::flush(self.get_mut())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<I> Seek for LenCursor<I> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        match pos {
+            SeekFrom::Start(p) => self.pos = p,
+            SeekFrom::Current(p) => {
+                let pos = self.pos as i64 + p;
+                if pos < 0 {
+                    return Err(Error::new(ErrorKind::InvalidInput, "seek to a negative position"));
                 }
+                self.pos = pos as u64;
+            }
+            SeekFrom::End(p) => {
+                self.pos = (self.length as i64 + p) as u64;
             }
         }
         Ok(self.pos)
@@ -0,0 +1,63 @@
+//! A small I/O error/result abstraction usable with or without `std`.
+//!
+//! With the `std` feature (the default), [`Error`], [`ErrorKind`] and
+//! [`Result`] are just re-exports of the matching `std::io` types, so this
+//! module is invisible to `std` users. Without `std`, we can't depend on
+//! `std::io::Error` (it allocates and carries a trait object), so we define a
+//! minimal `core`-only substitute with just the pieces [`ReadAt`][crate::ReadAt]
+//! and [`WriteAt`][crate::WriteAt] need: a kind and a static message.
+
+#[cfg(feature = "std")]
+pub use std::io::{Error, ErrorKind, Result};
+
+#[cfg(not(feature = "std"))]
+pub use self::no_std::{Error, ErrorKind, Result};
+
+#[cfg(not(feature = "std"))]
+mod no_std {
+    use core::fmt;
+
+    /// A `core`-only substitute for `std::io::ErrorKind`, covering just the
+    /// variants this crate produces itself.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[non_exhaustive]
+    pub enum ErrorKind {
+        InvalidInput,
+        InvalidData,
+        UnexpectedEof,
+        WriteZero,
+        Interrupted,
+        Other,
+    }
+
+    /// A `core`-only substitute for `std::io::Error`.
+    ///
+    /// Unlike `std::io::Error`, this never allocates: the message is a
+    /// `&'static str` rather than an arbitrary boxed error.
+    #[derive(Debug, Clone, Copy)]
+    pub struct Error {
+        kind: ErrorKind,
+        message: &'static str,
+    }
+
+    impl Error {
+        /// Create a new error with a kind and a static description.
+        pub fn new(kind: ErrorKind, message: &'static str) -> Self {
+            Error { kind, message }
+        }
+
+        /// The kind of error that occurred.
+        pub fn kind(&self) -> ErrorKind {
+            self.kind
+        }
+    }
+
+    impl fmt::Display for Error {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.message)
+        }
+    }
+
+    /// A `core`-only substitute for `std::io::Result`.
+    pub type Result<T> = core::result::Result<T, Error>;
+}
@@ -0,0 +1,368 @@
+//! Positioned I/O for files, and other types supporting random access.
+//!
+//! This library allows you to specify an offset for reads and writes, without
+//! changing the current position in a file. This is similar to `pread()` and
+//! `pwrite()` in C.
+//!
+//! The main traits are [`ReadAt`] and [`WriteAt`], which are implemented for
+//! `File`, `&[u8]`, and other types that support random access. There is also
+//! [`Size`], for types that know their own length, and [`Cursor`]/[`Slice`],
+//! which adapt a positioned I/O object into a regular `Read`/`Write`/`Seek`
+//! object or a bounded view of one.
+//!
+//! [`ReadAt`]: trait.ReadAt.html
+//! [`WriteAt`]: trait.WriteAt.html
+//! [`Size`]: trait.Size.html
+//! [`Cursor`]: struct.Cursor.html
+//! [`Slice`]: struct.Slice.html
+//!
+//! By default this crate depends on `std`, but the core traits and the
+//! [`Cursor`]/[`Slice`] adapters also work in `#![no_std]` environments (with
+//! `alloc`) by disabling the default `std` feature. Without `std`, anything
+//! that needs an actual OS file or an allocator (the `File` impls,
+//! [`BufReaderAt`], [`Concat`], [`MmapReadAt`]) is compiled out.
+//!
+//! [`BufReaderAt`]: struct.BufReaderAt.html
+//! [`Concat`]: struct.Concat.html
+//! [`MmapReadAt`]: struct.MmapReadAt.html
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod error;
+mod cursor;
+mod slice;
+#[cfg(feature = "std")]
+mod bufreader;
+#[cfg(feature = "std")]
+mod concat;
+#[cfg(all(feature = "std", feature = "mmap"))]
+mod mmap;
+
+pub use cursor::{Cursor, LenCursor, SizeCursor};
+pub use slice::Slice;
+#[cfg(feature = "std")]
+pub use bufreader::BufReaderAt;
+#[cfg(feature = "std")]
+pub use concat::Concat;
+#[cfg(all(feature = "std", feature = "mmap"))]
+pub use mmap::{MmapReadAt, MmapWriteAt};
+
+pub use error::{Error, ErrorKind, Result};
+
+/// Trait for reading at an offset, without changing the current position.
+///
+/// Inspired by `pread()` in C.
+pub trait ReadAt {
+    /// Reads bytes starting at `pos` into `buf`, returning the number of
+    /// bytes read.
+    ///
+    /// Just like `Read::read`, this may return fewer bytes than `buf`'s
+    /// length, and a return value of `0` typically means there is no more
+    /// data to be read at `pos`.
+    fn read_at(&self, pos: u64, buf: &mut [u8]) -> Result<usize>;
+
+    /// Reads bytes starting at `pos` to fill `buf`, returning an error if
+    /// the exact number of bytes could not be read.
+    fn read_exact_at(&self, mut pos: u64, mut buf: &mut [u8]) -> Result<()> {
+        while !buf.is_empty() {
+            match self.read_at(pos, buf) {
+                Ok(0) => break,
+                Ok(n) => {
+                    let tmp = buf;
+                    buf = &mut tmp[n..];
+                    pos += n as u64;
+                }
+                Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        if !buf.is_empty() {
+            Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                "failed to fill whole buffer",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Like `read_at`, but reads into a number of buffers, as if they were
+    /// one contiguous buffer, in a single operation.
+    ///
+    /// The default implementation isn't actually vectored: it reads into the
+    /// first non-empty buffer in `bufs` and returns, exactly like `read_at`
+    /// would. Implementors backed by a real scatter/gather syscall (see the
+    /// `File` impl on Unix, which uses `preadv`) should override this to
+    /// fill as many buffers as the underlying read covers.
+    #[cfg(feature = "std")]
+    fn read_vectored_at(&self, pos: u64, bufs: &mut [std::io::IoSliceMut<'_>]) -> Result<usize> {
+        match bufs.iter_mut().find(|buf| !buf.is_empty()) {
+            Some(buf) => self.read_at(pos, buf),
+            None => Ok(0),
+        }
+    }
+}
+
+/// Trait for writing at an offset, without changing the current position.
+///
+/// Inspired by `pwrite()` in C.
+pub trait WriteAt {
+    /// Writes bytes from `buf` starting at `pos`, returning the number of
+    /// bytes written.
+    fn write_at(&mut self, pos: u64, buf: &[u8]) -> Result<usize>;
+
+    /// Flushes any buffered data to the underlying storage.
+    fn flush(&mut self) -> Result<()>;
+
+    /// Writes all of `buf` starting at `pos`, returning an error if not all
+    /// bytes could be written.
+    fn write_all_at(&mut self, mut pos: u64, mut buf: &[u8]) -> Result<()> {
+        while !buf.is_empty() {
+            match self.write_at(pos, buf) {
+                Ok(0) => {
+                    return Err(Error::new(
+                        ErrorKind::WriteZero,
+                        "failed to write whole buffer",
+                    ))
+                }
+                Ok(n) => {
+                    buf = &buf[n..];
+                    pos += n as u64;
+                }
+                Err(ref e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+
+    /// Like `write_at`, but writes from a number of buffers, as if they were
+    /// one contiguous buffer, in a single operation.
+    ///
+    /// The default implementation isn't actually vectored: it writes from
+    /// the first non-empty buffer in `bufs` and returns, exactly like
+    /// `write_at` would. Implementors backed by a real scatter/gather
+    /// syscall (see the `File` impl on Unix, which uses `pwritev`) should
+    /// override this to write as many buffers as the underlying write
+    /// covers.
+    #[cfg(feature = "std")]
+    fn write_vectored_at(&mut self, pos: u64, bufs: &[std::io::IoSlice<'_>]) -> Result<usize> {
+        match bufs.iter().find(|buf| !buf.is_empty()) {
+            Some(buf) => self.write_at(pos, buf),
+            None => Ok(0),
+        }
+    }
+}
+
+/// Trait for objects that know their own total size.
+///
+/// Not every positioned I/O source knows its size up front (for example, a
+/// live network stream might not), so `size()` returns `None` in that case
+/// rather than an error.
+pub trait Size {
+    /// Returns the total size of the object, if known.
+    fn size(&self) -> Result<Option<u64>> {
+        Ok(None)
+    }
+}
+
+#[cfg(all(feature = "std", unix))]
+mod sys {
+    use std::fs::File;
+    use std::io;
+    use std::io::{IoSlice, IoSliceMut};
+    use std::os::unix::fs::FileExt;
+    use std::os::unix::io::AsRawFd;
+
+    use super::{ReadAt, Size, WriteAt};
+
+    impl ReadAt for File {
+        fn read_at(&self, pos: u64, buf: &mut [u8]) -> io::Result<usize> {
+            FileExt::read_at(self, buf, pos)
+        }
+
+        fn read_vectored_at(&self, pos: u64, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+            // `IoSliceMut` is guaranteed to have the same layout as `iovec`
+            // on Unix, so we can pass it straight to `preadv` for a single
+            // scatter read instead of looping over `read_at`.
+            let ret = unsafe {
+                libc::preadv(
+                    self.as_raw_fd(),
+                    bufs.as_ptr() as *const libc::iovec,
+                    bufs.len() as libc::c_int,
+                    pos as libc::off_t,
+                )
+            };
+            if ret < 0 {
+                Err(io::Error::last_os_error())
+            } else {
+                Ok(ret as usize)
+            }
+        }
+    }
+
+    impl WriteAt for File {
+        fn write_at(&mut self, pos: u64, buf: &[u8]) -> io::Result<usize> {
+            FileExt::write_at(self, buf, pos)
+        }
+
+        fn write_vectored_at(&mut self, pos: u64, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+            // `IoSlice` is guaranteed to have the same layout as `iovec` on
+            // Unix, so we can pass it straight to `pwritev` for a single
+            // gather write instead of looping over `write_at`.
+            let ret = unsafe {
+                libc::pwritev(
+                    self.as_raw_fd(),
+                    bufs.as_ptr() as *const libc::iovec,
+                    bufs.len() as libc::c_int,
+                    pos as libc::off_t,
+                )
+            };
+            if ret < 0 {
+                Err(io::Error::last_os_error())
+            } else {
+                Ok(ret as usize)
+            }
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            io::Write::flush(self)
+        }
+    }
+
+    impl Size for File {
+        fn size(&self) -> io::Result<Option<u64>> {
+            Ok(Some(self.metadata()?.len()))
+        }
+    }
+}
+
+#[cfg(all(feature = "std", windows))]
+mod sys {
+    use std::fs::File;
+    use std::io;
+    use std::os::windows::fs::FileExt;
+
+    use super::{ReadAt, Size, WriteAt};
+
+    impl ReadAt for File {
+        fn read_at(&self, pos: u64, buf: &mut [u8]) -> io::Result<usize> {
+            self.seek_read(buf, pos)
+        }
+    }
+
+    impl WriteAt for File {
+        fn write_at(&mut self, pos: u64, buf: &[u8]) -> io::Result<usize> {
+            self.seek_write(buf, pos)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            io::Write::flush(self)
+        }
+    }
+
+    impl Size for File {
+        fn size(&self) -> io::Result<Option<u64>> {
+            Ok(Some(self.metadata()?.len()))
+        }
+    }
+}
+
+impl ReadAt for [u8] {
+    fn read_at(&self, pos: u64, buf: &mut [u8]) -> Result<usize> {
+        if pos >= self.len() as u64 {
+            return Ok(0);
+        }
+        let start = pos as usize;
+        let bytes = core::cmp::min(buf.len(), self.len() - start);
+        buf[..bytes].copy_from_slice(&self[start..start + bytes]);
+        Ok(bytes)
+    }
+}
+
+impl Size for [u8] {
+    fn size(&self) -> Result<Option<u64>> {
+        Ok(Some(self.len() as u64))
+    }
+}
+
+impl WriteAt for [u8] {
+    fn write_at(&mut self, pos: u64, buf: &[u8]) -> Result<usize> {
+        if pos >= self.len() as u64 {
+            return Ok(0);
+        }
+        let start = pos as usize;
+        let bytes = core::cmp::min(buf.len(), self.len() - start);
+        self[start..start + bytes].copy_from_slice(&buf[..bytes]);
+        Ok(bytes)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl ReadAt for std::vec::Vec<u8> {
+    fn read_at(&self, pos: u64, buf: &mut [u8]) -> Result<usize> {
+        self.as_slice().read_at(pos, buf)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Size for std::vec::Vec<u8> {
+    fn size(&self) -> Result<Option<u64>> {
+        Ok(Some(self.len() as u64))
+    }
+}
+
+#[cfg(feature = "std")]
+impl WriteAt for std::vec::Vec<u8> {
+    fn write_at(&mut self, pos: u64, buf: &[u8]) -> Result<usize> {
+        let start = pos as usize;
+        let end = start + buf.len();
+        if self.len() < end {
+            self.resize(end, 0);
+        }
+        self[start..end].copy_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<T: ReadAt + ?Sized> ReadAt for &T {
+    fn read_at(&self, pos: u64, buf: &mut [u8]) -> Result<usize> {
+        (**self).read_at(pos, buf)
+    }
+}
+
+impl<T: Size + ?Sized> Size for &T {
+    fn size(&self) -> Result<Option<u64>> {
+        (**self).size()
+    }
+}
+
+impl<T: ReadAt + ?Sized> ReadAt for &mut T {
+    fn read_at(&self, pos: u64, buf: &mut [u8]) -> Result<usize> {
+        (**self).read_at(pos, buf)
+    }
+}
+
+impl<T: WriteAt + ?Sized> WriteAt for &mut T {
+    fn write_at(&mut self, pos: u64, buf: &[u8]) -> Result<usize> {
+        (**self).write_at(pos, buf)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        (**self).flush()
+    }
+}
+
+impl<T: Size + ?Sized> Size for &mut T {
+    fn size(&self) -> Result<Option<u64>> {
+        (**self).size()
+    }
+}
@@ -0,0 +1,172 @@
+use std::borrow::Borrow;
+use std::cmp::min;
+use std::fs::File;
+
+use memmap2::{Mmap, MmapMut};
+
+use crate::{ReadAt, Result, Size, WriteAt};
+
+/// A memory-mapped file, implementing `ReadAt`.
+///
+/// The file is mapped once, at construction time, so `read_at` is a plain
+/// memory copy rather than a `pread()` syscall. This is a good fit for
+/// workloads that issue lots of small, scattered reads, like the ones
+/// measured by this crate's `bench_read_at_random_seek` benchmark, since it
+/// avoids paying the per-call syscall overhead.
+///
+/// # Caveats
+///
+/// The mapping is taken over the file's length at the time `MmapReadAt` is
+/// created. If the file is truncated or grown afterwards by some other
+/// process or handle, the mapping is **not** kept in sync: reads past the
+/// original length keep returning `0`, and on some platforms truncating the
+/// underlying file while it is mapped is undefined behavior. Don't use this
+/// type for files that may change size for as long as the mapping exists.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::io;
+/// use std::io::Write;
+/// use positioned_io::{MmapReadAt, ReadAt};
+///
+/// # fn foo() -> io::Result<()> {
+/// let mut file = tempfile::tempfile()?;
+/// file.write_all(&[0, 1, 2, 3, 4, 5, 6, 7])?;
+///
+/// let map = MmapReadAt::new(file)?;
+///
+/// // A read that fits entirely within the file.
+/// let mut buf = [0; 4];
+/// let bytes = map.read_at(2, &mut buf)?;
+/// assert_eq!(bytes, 4);
+/// assert_eq!(buf, [2, 3, 4, 5]);
+///
+/// // A read that runs past the end is clamped to what's there.
+/// let bytes = map.read_at(6, &mut buf)?;
+/// assert_eq!(bytes, 2);
+/// assert_eq!(&buf[..2], [6, 7]);
+///
+/// // A read starting at or past the end returns 0.
+/// assert_eq!(map.read_at(8, &mut buf)?, 0);
+/// # Ok(())
+/// # }
+/// # fn main() { foo().unwrap(); }
+/// ```
+#[derive(Debug)]
+pub struct MmapReadAt<F = File> {
+    file: F,
+    map: Mmap,
+}
+
+impl<F: Borrow<File>> MmapReadAt<F> {
+    /// Map the current contents of `file` into memory.
+    ///
+    /// `file` may be a plain `File` (which is then owned by the returned
+    /// `MmapReadAt`), or anything that borrows one, such as an `Arc<File>`
+    /// shared with other readers.
+    pub fn new(file: F) -> Result<Self> {
+        let map = unsafe { Mmap::map(file.borrow())? };
+        Ok(MmapReadAt { file, map })
+    }
+
+    /// Borrow the underlying file.
+    pub fn get_ref(&self) -> &F {
+        &self.file
+    }
+
+    /// Consume `self`, returning the underlying file.
+    pub fn into_inner(self) -> F {
+        self.file
+    }
+}
+
+impl<F> ReadAt for MmapReadAt<F> {
+    fn read_at(&self, pos: u64, buf: &mut [u8]) -> Result<usize> {
+        let len = self.map.len() as u64;
+        if pos >= len {
+            return Ok(0);
+        }
+        let start = pos as usize;
+        let bytes = min(buf.len() as u64, len - pos) as usize;
+        buf[..bytes].copy_from_slice(&self.map[start..start + bytes]);
+        Ok(bytes)
+    }
+}
+
+impl<F> Size for MmapReadAt<F> {
+    fn size(&self) -> Result<Option<u64>> {
+        Ok(Some(self.map.len() as u64))
+    }
+}
+
+/// A memory-mapped file, implementing `ReadAt` and `WriteAt`.
+///
+/// Like [`MmapReadAt`][MmapReadAt], but maps the file writable, so
+/// `write_at` copies directly into the mapping instead of issuing a
+/// `pwrite()`. Call [`flush`][WriteAt::flush] to make writes durable;
+/// dropping an `MmapWriteAt` does not flush. The same truncation/growth caveats as
+/// [`MmapReadAt`][MmapReadAt] apply.
+///
+/// [MmapReadAt]: struct.MmapReadAt.html
+/// [WriteAt::flush]: trait.WriteAt.html#tymethod.flush
+#[derive(Debug)]
+pub struct MmapWriteAt<F = File> {
+    file: F,
+    map: MmapMut,
+}
+
+impl<F: Borrow<File>> MmapWriteAt<F> {
+    /// Map the current contents of `file` into memory for reading and
+    /// writing.
+    pub fn new(file: F) -> Result<Self> {
+        let map = unsafe { MmapMut::map_mut(file.borrow())? };
+        Ok(MmapWriteAt { file, map })
+    }
+
+    /// Borrow the underlying file.
+    pub fn get_ref(&self) -> &F {
+        &self.file
+    }
+
+    /// Consume `self`, returning the underlying file.
+    pub fn into_inner(self) -> F {
+        self.file
+    }
+}
+
+impl<F> ReadAt for MmapWriteAt<F> {
+    fn read_at(&self, pos: u64, buf: &mut [u8]) -> Result<usize> {
+        let len = self.map.len() as u64;
+        if pos >= len {
+            return Ok(0);
+        }
+        let start = pos as usize;
+        let bytes = min(buf.len() as u64, len - pos) as usize;
+        buf[..bytes].copy_from_slice(&self.map[start..start + bytes]);
+        Ok(bytes)
+    }
+}
+
+impl<F> WriteAt for MmapWriteAt<F> {
+    fn write_at(&mut self, pos: u64, buf: &[u8]) -> Result<usize> {
+        let len = self.map.len() as u64;
+        if pos >= len {
+            return Ok(0);
+        }
+        let start = pos as usize;
+        let bytes = min(buf.len() as u64, len - pos) as usize;
+        self.map[start..start + bytes].copy_from_slice(&buf[..bytes]);
+        Ok(bytes)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.map.flush()
+    }
+}
+
+impl<F> Size for MmapWriteAt<F> {
+    fn size(&self) -> Result<Option<u64>> {
+        Ok(Some(self.map.len() as u64))
+    }
+}
@@ -1,9 +1,8 @@
-use std::cmp::min;
-use std::io;
+use core::cmp::min;
+use core::ops::Bound;
+use core::ops::RangeBounds;
 
-use super::{ReadAt, WriteAt, Size};
-use std::ops::RangeBounds;
-use std::ops::Bound;
+use crate::{Error, ErrorKind, ReadAt, Result, Size, WriteAt};
 
 /// A window into another `ReadAt` or `WriteAt`.
 ///
@@ -70,9 +69,9 @@ impl<I> Slice<I> {
             Bound::Unbounded => 0,
         };
         let size = match bounds.end_bound() {
-            Bound::Included(&end) => if end == u64::max_value() { end.saturating_sub(offset).saturating_add(1) } else { (end + 1).saturating_sub(offset) }
+            Bound::Included(&end) => if end == u64::MAX { end.saturating_sub(offset).saturating_add(1) } else { (end + 1).saturating_sub(offset) }
             Bound::Excluded(&end) => end.saturating_sub(offset),
-            Bound::Unbounded => u64::max_value(),
+            Bound::Unbounded => u64::MAX,
         };
         Slice {
             io,
@@ -91,35 +90,83 @@ impl<I: Size> Slice<I> {
     ///
     /// Note that you can create a larger slice by passing a larger size to
     /// `new()`, but it won't do you any good for reading.
-    pub fn new_to_end(io: I, offset: u64) -> io::Result<Self> {
+    pub fn new_to_end(io: I, offset: u64) -> Result<Self> {
         match io.size() {
             Ok(Some(size)) => Ok(Self::new(io, offset..size)),
-            _ => Err(io::Error::new(io::ErrorKind::InvalidData, "unknown base size")),
+            _ => Err(Error::new(ErrorKind::InvalidData, "unknown base size")),
         }
     }
 }
 
 impl<I: ReadAt> ReadAt for Slice<I> {
-    fn read_at(&self, pos: u64, buf: &mut [u8]) -> io::Result<usize> {
+    fn read_at(&self, pos: u64, buf: &mut [u8]) -> Result<usize> {
         let bytes = self.avail(pos, buf.len());
         self.io.read_at(pos + self.offset, &mut buf[..bytes])
     }
+
+    /// Clamps `bufs` to the slice's bounds and forwards to the inner
+    /// `read_vectored_at`.
+    ///
+    /// Note that if `I` doesn't override `read_vectored_at` (in this crate,
+    /// anything but `File` on Unix and `Slice`), the default implementation it falls back to only fills
+    /// the first non-empty buffer, so a clamped multi-buffer request through
+    /// a `Slice` silently returns just that buffer's worth rather than
+    /// scattering across all of `bufs`.
+    #[cfg(feature = "std")]
+    fn read_vectored_at(&self, pos: u64, bufs: &mut [std::io::IoSliceMut<'_>]) -> Result<usize> {
+        let total = bufs.iter().map(|buf| buf.len()).sum();
+        let mut remaining = self.avail(pos, total);
+        let mut clamped = std::vec::Vec::with_capacity(bufs.len());
+        for buf in bufs.iter_mut() {
+            if remaining == 0 {
+                break;
+            }
+            let take = min(remaining, buf.len());
+            remaining -= take;
+            clamped.push(std::io::IoSliceMut::new(&mut buf[..take]));
+        }
+        self.io.read_vectored_at(pos + self.offset, &mut clamped)
+    }
 }
 
 impl<I: WriteAt> WriteAt for Slice<I> {
-    fn write_at(&mut self, pos: u64, buf: &[u8]) -> io::Result<usize> {
+    fn write_at(&mut self, pos: u64, buf: &[u8]) -> Result<usize> {
         let bytes = self.avail(pos, buf.len());
         self.io.write_at(pos + self.offset, &buf[..bytes])
     }
 
-    fn flush(&mut self) -> io::Result<()> {
+    /// Clamps `bufs` to the slice's bounds and forwards to the inner
+    /// `write_vectored_at`.
+    ///
+    /// Note that if `I` doesn't override `write_vectored_at` (in this crate,
+    /// anything but `File` on Unix and `Slice`), the default implementation it falls back to only writes
+    /// the first non-empty buffer, so a clamped multi-buffer request through
+    /// a `Slice` silently writes just that buffer's worth rather than
+    /// gathering all of `bufs`.
+    #[cfg(feature = "std")]
+    fn write_vectored_at(&mut self, pos: u64, bufs: &[std::io::IoSlice<'_>]) -> Result<usize> {
+        let total = bufs.iter().map(|buf| buf.len()).sum();
+        let mut remaining = self.avail(pos, total);
+        let mut clamped = std::vec::Vec::with_capacity(bufs.len());
+        for buf in bufs.iter() {
+            if remaining == 0 {
+                break;
+            }
+            let take = min(remaining, buf.len());
+            remaining -= take;
+            clamped.push(std::io::IoSlice::new(&buf[..take]));
+        }
+        self.io.write_vectored_at(pos + self.offset, &clamped)
+    }
+
+    fn flush(&mut self) -> Result<()> {
         self.io.flush()
     }
 }
 
 impl<I> Size for Slice<I> {
-    fn size(&self) -> io::Result<Option<u64>> {
-        if self.size == u64::max_value() {
+    fn size(&self) -> Result<Option<u64>> {
+        if self.size == u64::MAX {
             Ok(None)
         } else {
             Ok(Some(self.size))